@@ -0,0 +1,141 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2022.
+
+//! Panic handling and low-level debug output for the HiFive Inventor board.
+
+use core::fmt::Write;
+use core::panic::PanicInfo;
+
+use kernel::debug;
+use kernel::debug::IoWrite;
+use kernel::utilities::registers::debug::RegisterDebugValue;
+use kernel::utilities::registers::interfaces::Readable;
+use rv32i::csr::CSR;
+
+use crate::CHIP;
+use crate::PROCESSES;
+use crate::PROCESS_PRINTER;
+
+/// Register layouts for decoding the RISC-V trap cause CSRs into named fields.
+///
+/// The arch crate reads these CSRs as plain integers, so we describe the
+/// relevant bitfields here to turn the snapshot captured on a fault into a
+/// human-readable dump. `register_debug_info!` attaches the [`RegisterDebugInfo`]
+/// implementation that lets a captured value be wrapped in a
+/// [`RegisterDebugValue`].
+///
+/// [`RegisterDebugInfo`]: kernel::utilities::registers::debug::RegisterDebugInfo
+/// [`RegisterDebugValue`]: kernel::utilities::registers::debug::RegisterDebugValue
+mod trap {
+    use kernel::utilities::registers::{register_bitfields, register_debug_info};
+
+    register_bitfields![usize,
+        pub Mcause [
+            interrupt OFFSET(31) NUMBITS(1) [
+                Exception = 0,
+                Interrupt = 1
+            ],
+            exception_code OFFSET(0) NUMBITS(31) [
+                InstructionAddressMisaligned = 0,
+                InstructionAccessFault = 1,
+                IllegalInstruction = 2,
+                Breakpoint = 3,
+                LoadAddressMisaligned = 4,
+                LoadAccessFault = 5,
+                StoreAddressMisaligned = 6,
+                StoreAccessFault = 7,
+                EnvironmentCallFromUMode = 8,
+                EnvironmentCallFromSMode = 9,
+                EnvironmentCallFromMMode = 11,
+                InstructionPageFault = 12,
+                LoadPageFault = 13,
+                StorePageFault = 15
+            ]
+        ],
+        pub Mstatus [
+            mie OFFSET(3) NUMBITS(1) [
+                Disabled = 0,
+                Enabled = 1
+            ],
+            mpie OFFSET(7) NUMBITS(1) [
+                Disabled = 0,
+                Enabled = 1
+            ],
+            mpp OFFSET(11) NUMBITS(2) [
+                User = 0,
+                Supervisor = 1,
+                Machine = 3
+            ]
+        ]
+    ];
+
+    register_debug_info!(usize, Mcause::Register, "Mcause", [
+        "interrupt" => (Mcause::interrupt, Mcause::interrupt::Value),
+        "exception_code" => (Mcause::exception_code, Mcause::exception_code::Value),
+    ]);
+
+    register_debug_info!(usize, Mstatus::Register, "Mstatus", [
+        "mie" => (Mstatus::mie, Mstatus::mie::Value),
+        "mpie" => (Mstatus::mpie, Mstatus::mpie::Value),
+        "mpp" => (Mstatus::mpp, Mstatus::mpp::Value),
+    ]);
+}
+
+struct Writer {}
+
+static mut WRITER: Writer = Writer {};
+
+impl Write for Writer {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.write(s.as_bytes());
+        Ok(())
+    }
+}
+
+impl IoWrite for Writer {
+    fn write(&mut self, buf: &[u8]) -> usize {
+        // Create a new UART driver directly, bypassing the regular
+        // virtualization layer, which is no longer trustworthy on the panic
+        // path.
+        let uart = sifive::uart::Uart::new(e310_g003::uart::UART0_BASE, 16_000_000);
+        uart.transmit_sync(buf);
+        buf.len()
+    }
+}
+
+/// Panic handler.
+#[cfg(not(test))]
+#[no_mangle]
+#[panic_handler]
+pub unsafe fn panic_fmt(pi: &PanicInfo) -> ! {
+    let writer = &mut *core::ptr::addr_of_mut!(WRITER);
+
+    // Decode the RISC-V trap cause CSRs into a human-readable form before the
+    // generic panic dump. `mcause` and `mstatus` are captured by copy (the
+    // backing CSR may be clobbered before the dump completes) and wrapped in a
+    // `RegisterDebugValue` so their bitfields print as named variants (e.g.
+    // `Mcause { interrupt: Exception, exception_code: IllegalInstruction }`)
+    // rather than as opaque integers. `mtval`/`mepc` are plain addresses and
+    // are printed as hex.
+    let _ = writer.write_fmt(format_args!(
+        "\r\n{:?}\r\n{:?}\r\nmtval: {:#010x}\r\nmepc:  {:#010x}\r\n",
+        RegisterDebugValue::<usize, trap::Mcause::Register>::new(CSR.mcause.get()),
+        RegisterDebugValue::<usize, trap::Mstatus::Register>::new(CSR.mstatus.get()),
+        CSR.mtval.get(),
+        CSR.mepc.get(),
+    ));
+
+    debug::panic_print(
+        writer,
+        pi,
+        &rv32i::support::nop,
+        &*core::ptr::addr_of!(PROCESSES),
+        &*core::ptr::addr_of!(CHIP),
+        &*core::ptr::addr_of!(PROCESS_PRINTER),
+    );
+
+    loop {
+        rv32i::support::nop();
+    }
+}
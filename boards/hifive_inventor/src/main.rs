@@ -15,7 +15,7 @@ use kernel::dynamic_deferred_call::{DynamicDeferredCall, DynamicDeferredCallClie
 use kernel::hil;
 use kernel::platform::scheduler_timer::VirtualSchedulerTimer;
 use kernel::platform::{KernelResources, SyscallDriverLookup};
-use kernel::scheduler::cooperative::CooperativeSched;
+use kernel::scheduler::mlfq::MLFQSched;
 use kernel::utilities::registers::interfaces::ReadWriteable;
 use kernel::{create_capability, debug, static_init};
 use rv32i::csr;
@@ -54,7 +54,12 @@ struct HiFiveInventor {
         'static,
         VirtualMuxAlarm<'static, sifive::clint::Clint<'static>>,
     >,
-    scheduler: &'static CooperativeSched<'static>,
+    gpio: &'static capsules::gpio::GPIO<'static, sifive::gpio::GpioPin<'static>>,
+    pwm: &'static capsules::pwm::Pwm<'static>,
+    scheduler: &'static MLFQSched<
+        'static,
+        VirtualMuxAlarm<'static, sifive::clint::Clint<'static>>,
+    >,
     scheduler_timer:
         &'static VirtualSchedulerTimer<VirtualMuxAlarm<'static, sifive::clint::Clint<'static>>>,
 }
@@ -68,6 +73,8 @@ impl SyscallDriverLookup for HiFiveInventor {
         match driver_num {
             capsules::console::DRIVER_NUM => f(Some(self.console)),
             capsules::alarm::DRIVER_NUM => f(Some(self.alarm)),
+            capsules::gpio::DRIVER_NUM => f(Some(self.gpio)),
+            capsules::pwm::DRIVER_NUM => f(Some(self.pwm)),
             capsules::low_level_debug::DRIVER_NUM => f(Some(self.lldb)),
             _ => f(None),
         }
@@ -81,7 +88,8 @@ impl KernelResources<e310_g003::chip::E310x<'static, E310G003DefaultPeripherals<
     type SyscallFilter = ();
     type ProcessFault = ();
     type CredentialsCheckingPolicy = ();
-    type Scheduler = CooperativeSched<'static>;
+    type Scheduler =
+        MLFQSched<'static, VirtualMuxAlarm<'static, sifive::clint::Clint<'static>>>;
     type SchedulerTimer =
         VirtualSchedulerTimer<VirtualMuxAlarm<'static, sifive::clint::Clint<'static>>>;
     type WatchDog = ();
@@ -145,9 +153,6 @@ pub unsafe fn main() {
 
     peripherals.e310x.watchdog.disable();
     peripherals.e310x.rtc.disable();
-    peripherals.e310x.pwm0.disable();
-    peripherals.e310x.pwm1.disable();
-    peripherals.e310x.pwm2.disable();
     peripherals.e310x.uart1.disable();
 
     // initialize capabilities
@@ -259,6 +264,42 @@ pub unsafe fn main() {
     )
     .finalize(components::low_level_debug_component_static!());
 
+    // Expose the board's GPIO pins to userspace. Finalizing the component
+    // registers the capsule as the interrupt client of each pin (via
+    // `set_client`), so an edge/level interrupt on a pin is delivered to the
+    // capsule as an upcall. The hardware IRQs themselves are fanned out to the
+    // pins by the E310's existing PLIC interrupt service, which calls
+    // `handle_interrupt()` on the relevant `gpio_port[n]`; this board adds the
+    // capsule end of that path, not the PLIC dispatch.
+    let gpio = components::gpio::GpioComponent::new(
+        board_kernel,
+        capsules::gpio::DRIVER_NUM,
+        components::gpio_component_helper!(
+            sifive::gpio::GpioPin,
+            0 => &peripherals.e310x.gpio_port[0],
+            1 => &peripherals.e310x.gpio_port[1],
+            2 => &peripherals.e310x.gpio_port[2],
+            3 => &peripherals.e310x.gpio_port[3],
+            4 => &peripherals.e310x.gpio_port[4],
+            5 => &peripherals.e310x.gpio_port[5],
+            9 => &peripherals.e310x.gpio_port[9],
+            10 => &peripherals.e310x.gpio_port[10],
+            11 => &peripherals.e310x.gpio_port[11],
+            12 => &peripherals.e310x.gpio_port[12],
+            13 => &peripherals.e310x.gpio_port[13]
+        ),
+    )
+    .finalize(components::gpio_component_buf!(sifive::gpio::GpioPin));
+
+    // Expose the three PWM peripherals to userspace.
+    let pwm = components::pwm::PwmVirtualComponent::new(board_kernel, capsules::pwm::DRIVER_NUM)
+        .finalize(components::pwm_component_helper!(
+            sifive::pwm::Pwm,
+            &peripherals.e310x.pwm0,
+            &peripherals.e310x.pwm1,
+            &peripherals.e310x.pwm2
+        ));
+
     debug!("HiFive1 initialization complete. Entering main loop.");
 
     // These symbols are defined in the linker script.
@@ -273,8 +314,16 @@ pub unsafe fn main() {
         static _eappmem: u8;
     }
 
-    let scheduler = components::sched::cooperative::CooperativeComponent::new(&PROCESSES)
-        .finalize(components::coop_component_helper!(NUM_PROCS));
+    // Preemptive multi-level feedback queue scheduler. New processes enter the
+    // top queue; a process that exhausts its time slice is demoted a level,
+    // while one that yields or blocks early keeps its level. A periodic
+    // priority boost (driven off the shared `MuxAlarm`) restores every process
+    // to the top queue so that no process can be starved.
+    let scheduler = components::sched::mlfq::MLFQComponent::new(mux_alarm, &PROCESSES)
+        .finalize(components::mlfq_component_helper!(
+            sifive::clint::Clint,
+            NUM_PROCS
+        ));
 
     let scheduler_timer = static_init!(
         VirtualSchedulerTimer<VirtualMuxAlarm<'static, sifive::clint::Clint<'static>>>,
@@ -284,6 +333,8 @@ pub unsafe fn main() {
     let hifive1 = HiFiveInventor {
         console: console,
         alarm: alarm,
+        gpio: gpio,
+        pwm: pwm,
         lldb: lldb,
         scheduler,
         scheduler_timer,
@@ -51,6 +51,40 @@ pub trait FieldValueEnumSeq<U: UIntLike> {
     /// Importantly, `data` is invoked for every type in the sequence, and
     /// every invocation of `data` is followed by a single invocation of `f`.
     fn recurse_try_from_value(data: &mut impl FnMut() -> U, f: &mut impl FnMut(&dyn fmt::Debug));
+
+    /// Iterates over the sequence of types exactly like
+    /// [`recurse_try_from_value`](FieldValueEnumSeq::recurse_try_from_value),
+    /// but preserves whether the field value matched a known variant.
+    ///
+    /// For every type in the sequence, `data` is invoked exactly once to obtain
+    /// the numeric field value, followed by exactly one invocation of `f` with
+    /// that numeric value and, when
+    /// [`try_from_value`](crate::fields::TryFromValue::try_from_value) resolves
+    /// it to a known variant, a [`fmt::Debug`] reference to that variant
+    /// (`None` otherwise).
+    ///
+    /// This is the lower-level primitive on top of which the `fmt::Debug`
+    /// representation of [`RegisterDebugValue`] is built; it keeps the raw value
+    /// and the decoded variant separate so that callers can construct
+    /// machine-readable dumps.
+    fn recurse_fields(data: &mut impl FnMut() -> U, f: &mut impl FnMut(U, Option<&dyn fmt::Debug>));
+
+    /// Iterates over the sequence of types, comparing two captured register
+    /// values field by field.
+    ///
+    /// For every type in the sequence, `data` is invoked exactly once to obtain
+    /// a `(old, new)` pair of numeric field values, followed by exactly one
+    /// invocation of `f`. `f` receives both raw values, their optionally-decoded
+    /// variants (`None` when
+    /// [`try_from_value`](crate::fields::TryFromValue::try_from_value) does not
+    /// resolve them), and a flag indicating whether the two values differ. It is
+    /// up to `f` to skip unchanged fields.
+    ///
+    /// This backs [`RegisterDebugValue::diff`].
+    fn recurse_fields_diff(
+        data: &mut impl FnMut() -> (U, U),
+        f: &mut impl FnMut(U, U, Option<&dyn fmt::Debug>, Option<&dyn fmt::Debug>, bool),
+    );
 }
 
 /// End-of-list type for the [`FieldValueEnumSeq`] sequence.
@@ -58,6 +92,18 @@ pub enum FieldValueEnumNil {}
 impl<U: UIntLike> FieldValueEnumSeq<U> for FieldValueEnumNil {
     fn recurse_try_from_value(_data: &mut impl FnMut() -> U, _f: &mut impl FnMut(&dyn fmt::Debug)) {
     }
+
+    fn recurse_fields(
+        _data: &mut impl FnMut() -> U,
+        _f: &mut impl FnMut(U, Option<&dyn fmt::Debug>),
+    ) {
+    }
+
+    fn recurse_fields_diff(
+        _data: &mut impl FnMut() -> (U, U),
+        _f: &mut impl FnMut(U, U, Option<&dyn fmt::Debug>, Option<&dyn fmt::Debug>, bool),
+    ) {
+    }
 }
 
 /// List element for the [`FieldValueEnumSeq`] sequence.
@@ -92,6 +138,43 @@ impl<U: UIntLike, H: TryFromValue<U, EnumType = H> + fmt::Debug, T: FieldValueEn
         // Continue the recursion:
         T::recurse_try_from_value(data, f)
     }
+
+    fn recurse_fields(data: &mut impl FnMut() -> U, f: &mut impl FnMut(U, Option<&dyn fmt::Debug>)) {
+        // As with `recurse_try_from_value`, call `data` _exactly_ once and `f`
+        // _exactly_ once, but hand the raw value and the optionally-decoded
+        // variant to `f` separately rather than collapsing them.
+        let extracted_value = data();
+
+        match H::try_from_value(extracted_value) {
+            Some(v) => f(extracted_value, Some(&v)),
+            None => f(extracted_value, None),
+        }
+
+        // Continue the recursion:
+        T::recurse_fields(data, f)
+    }
+
+    fn recurse_fields_diff(
+        data: &mut impl FnMut() -> (U, U),
+        f: &mut impl FnMut(U, U, Option<&dyn fmt::Debug>, Option<&dyn fmt::Debug>, bool),
+    ) {
+        // Call `data` _exactly_ once to obtain the old/new pair and `f`
+        // _exactly_ once with both decoded variants and a changed flag.
+        let (old, new) = data();
+
+        let old_decoded = H::try_from_value(old);
+        let new_decoded = H::try_from_value(new);
+        f(
+            old,
+            new,
+            old_decoded.as_ref().map(|v| v as &dyn fmt::Debug),
+            new_decoded.as_ref().map(|v| v as &dyn fmt::Debug),
+            old != new,
+        );
+
+        // Continue the recursion:
+        T::recurse_fields_diff(data, f)
+    }
 }
 
 /// [`RegisterDebugInfo`] exposes debugging information from register types.
@@ -167,6 +250,188 @@ where
     pub(crate) _reg: core::marker::PhantomData<E>,
 }
 
+impl<T, E> RegisterDebugValue<T, E>
+where
+    T: UIntLike,
+    E: RegisterDebugInfo<T>,
+{
+    /// Capture a raw register value for debugging.
+    ///
+    /// This takes an owned, numeric copy of a register's value rather than a
+    /// reference to the backing register. It is the entry point used when the
+    /// underlying register is no longer accessible at the point the debug
+    /// representation is produced, such as a trap cause CSR snapshotted on the
+    /// panic path.
+    pub fn new(data: T) -> Self {
+        RegisterDebugValue {
+            data,
+            _reg: core::marker::PhantomData,
+        }
+    }
+
+    /// Visit every field of the captured register in declaration order.
+    ///
+    /// For each field, `f` is invoked with the field's name, its raw numeric
+    /// value, and — when that value corresponds to a known variant — a
+    /// [`fmt::Debug`] reference to the decoded variant (`None` otherwise). This
+    /// is the structured counterpart to the [`fmt::Debug`] implementation:
+    /// rather than rendering into a formatter, it exposes the decoded state so
+    /// that callers (such as a console capsule emitting `key=value` lines) can
+    /// build machine-readable dumps or branch on an individual field's variant.
+    pub fn fields_iter(&self, mut f: impl FnMut(&'static str, T, Option<&dyn fmt::Debug>)) {
+        // Iterators over the field names and fields, guaranteed to match the
+        // order of the `FieldValueEnumTypes` sequence.
+        let mut names = E::field_names().iter();
+        let mut fields = E::fields().iter();
+
+        let mut data = || fields.next().unwrap().read(self.data);
+        let mut visit = |raw, decoded: Option<&dyn fmt::Debug>| {
+            f(names.next().unwrap(), raw, decoded);
+        };
+
+        E::FieldValueEnumTypes::recurse_fields(&mut data, &mut visit);
+    }
+
+    /// Produce a [`fmt::Debug`] view that lists only the fields whose value
+    /// differs between this snapshot and `prev`.
+    ///
+    /// Each differing field is rendered as `field_name: OldVariant ->
+    /// NewVariant`, where `OldVariant`/`NewVariant` are the decoded variants of
+    /// `prev` and `self` respectively, falling back to the raw numeric value
+    /// when no known variant matches. Fields that are unchanged are omitted
+    /// entirely, which is far more legible than two full register dumps when
+    /// tracing what an event (such as trap entry) mutated.
+    pub fn diff(&self, prev: &Self) -> impl fmt::Debug {
+        RegisterDebugValueDiff::<T, E> {
+            old: prev.data,
+            new: self.data,
+            _reg: core::marker::PhantomData,
+        }
+    }
+}
+
+/// A [`fmt::Debug`] view over the difference between two [`RegisterDebugValue`]
+/// snapshots, as produced by [`RegisterDebugValue::diff`].
+struct RegisterDebugValueDiff<T, E>
+where
+    T: UIntLike,
+    E: RegisterDebugInfo<T>,
+{
+    old: T,
+    new: T,
+    _reg: core::marker::PhantomData<E>,
+}
+
+impl<T, E> fmt::Debug for RegisterDebugValueDiff<T, E>
+where
+    T: UIntLike,
+    E: RegisterDebugInfo<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct(E::name());
+
+        let mut names = E::field_names().iter();
+        let mut fields = E::fields().iter();
+
+        let mut data = || {
+            let field = fields.next().unwrap();
+            (field.read(self.old), field.read(self.new))
+        };
+        let mut visit = |old_raw: T,
+                         new_raw: T,
+                         old_decoded: Option<&dyn fmt::Debug>,
+                         new_decoded: Option<&dyn fmt::Debug>,
+                         changed: bool| {
+            let name = names.next().unwrap();
+            if changed {
+                // Prefer the decoded variant, falling back to the raw value.
+                let transition = FieldTransition {
+                    old: old_decoded.unwrap_or(&old_raw),
+                    new: new_decoded.unwrap_or(&new_raw),
+                };
+                debug_struct.field(name, &transition);
+            }
+        };
+
+        E::FieldValueEnumTypes::recurse_fields_diff(&mut data, &mut visit);
+
+        debug_struct.finish()
+    }
+}
+
+/// Renders a single changed field as `old -> new` in a register diff.
+struct FieldTransition<'a> {
+    old: &'a dyn fmt::Debug,
+    new: &'a dyn fmt::Debug,
+}
+
+impl fmt::Debug for FieldTransition<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} -> {:?}", self.old, self.new)
+    }
+}
+
+/// Emit a [`RegisterDebugInfo`] implementation for a register.
+///
+/// This is the machinery that attaches debug information to a register: for a
+/// given register it wires up the register name, the per-field names and
+/// [`Field`] descriptors, and the [`FieldValueEnumCons`]/[`FieldValueEnumNil`]
+/// type sequence built from the fields' generated value enums. Having this
+/// implementation is what lets a captured register value be wrapped in a
+/// [`RegisterDebugValue`] and rendered with named field variants.
+///
+/// It is invoked once per register, directly alongside the register's
+/// definition (as the trap-CSR decoding on a board's panic path does). The
+/// [`register_bitfields!`] macro does not invoke it, so a register defined
+/// with that macro has no [`RegisterDebugInfo`] implementation until this
+/// macro is invoked for it.
+///
+/// The entries are given in declaration order as `"field_name" => (field,
+/// ValueEnum)`, where `field` is the [`Field`] descriptor for the field and
+/// `ValueEnum` is its generated value enum (the type returned by
+/// [`Field::read_as_enum`](crate::fields::Field::read_as_enum)). The ordering
+/// of the three per-field sequences this produces — names, fields, and enum
+/// types — is kept identical, as the [`RegisterDebugInfo`] contract requires.
+///
+/// [`register_bitfields!`]: crate::register_bitfields
+#[macro_export]
+macro_rules! register_debug_info {
+    (
+        $valtype:ty, $reg:ty, $name:expr,
+        [ $( $field_name:expr => ($field:expr, $enum:ty) ),* $(,)? ]
+    ) => {
+        impl $crate::debug::RegisterDebugInfo<$valtype> for $reg {
+            type FieldValueEnumTypes =
+                $crate::register_debug_info!(@seq $valtype, [ $( $enum ),* ]);
+
+            fn name() -> &'static str {
+                $name
+            }
+
+            fn field_names() -> &'static [&'static str] {
+                &[ $( $field_name ),* ]
+            }
+
+            fn fields() -> &'static [$crate::fields::Field<$valtype, Self>] {
+                &[ $( $field ),* ]
+            }
+        }
+    };
+
+    // Recursively build the `FieldValueEnumCons`/`FieldValueEnumNil` type
+    // sequence from the list of field value enums.
+    (@seq $valtype:ty, []) => {
+        $crate::debug::FieldValueEnumNil
+    };
+    (@seq $valtype:ty, [ $head:ty $(, $tail:ty )* ]) => {
+        $crate::debug::FieldValueEnumCons<
+            $valtype,
+            $head,
+            $crate::register_debug_info!(@seq $valtype, [ $( $tail ),* ])
+        >
+    };
+}
+
 impl<T, E> fmt::Debug for RegisterDebugValue<T, E>
 where
     T: UIntLike + 'static,
@@ -177,27 +442,102 @@ where
         // This is using the core library's formatting facilities to produce an
         // output similar to Rust's own derive-Debug implementation on structs.
         //
-        // We start by printing the struct's name and opening braces:
+        // We start by printing the struct's name and opening braces, then defer
+        // to the structured `fields_iter` API for the actual field decoding,
+        // rendering the decoded variant where one exists and falling back to the
+        // raw numeric value otherwise.
         let mut debug_struct = f.debug_struct(E::name());
 
-        // Now, obtain iterators over both the struct's field types and
-        // names. They are guaranteed to match up:
-        let mut names = E::field_names().iter();
-        let mut fields = E::fields().iter();
+        self.fields_iter(|name, raw, decoded| match decoded {
+            Some(variant) => {
+                debug_struct.field(name, variant);
+            }
+            None => {
+                debug_struct.field(name, &raw);
+            }
+        });
 
-        // To actually resolve the field's known values (encoded in the field
-        // enum type's variants), we need to recurse through those field
-        // types. Their ordering is guaranteed to match up with the above
-        // calls. For more information on what these closures do and how they
-        // are invoked, consult the documentation of `recurse_try_from_value`.
-        let mut data = || fields.next().unwrap().read(self.data);
-        let mut debug_field = |f: &dyn fmt::Debug| {
-            debug_struct.field(names.next().unwrap(), f);
-        };
+        debug_struct.finish()
+    }
+}
 
-        // Finally, recurse through all the fields:
-        E::FieldValueEnumTypes::recurse_try_from_value(&mut data, &mut debug_field);
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::{format, string::String, vec::Vec};
 
-        debug_struct.finish()
+    use super::RegisterDebugValue;
+
+    // A small register with two decodable fields, used to exercise the
+    // structured debug APIs.
+    crate::register_bitfields![u32,
+        Status [
+            mode OFFSET(0) NUMBITS(2) [
+                Off = 0,
+                On = 1,
+                Standby = 2
+            ],
+            ready OFFSET(2) NUMBITS(1) [
+                NotReady = 0,
+                Ready = 1
+            ]
+        ]
+    ];
+
+    crate::register_debug_info!(u32, Status::Register, "Status", [
+        "mode" => (Status::mode, Status::mode::Value),
+        "ready" => (Status::ready, Status::ready::Value),
+    ]);
+
+    #[test]
+    fn fields_iter_exposes_decoded_and_raw() {
+        // mode = 0b01 = On, ready = 0b1 = Ready.
+        let reg = RegisterDebugValue::<u32, Status::Register>::new(0b101);
+        let mut fields: Vec<(&'static str, u32, Option<String>)> = Vec::new();
+        reg.fields_iter(|name, raw, decoded| {
+            fields.push((name, raw, decoded.map(|d| format!("{:?}", d))));
+        });
+        assert_eq!(
+            fields,
+            [
+                ("mode", 0b01, Some(String::from("On"))),
+                ("ready", 0b1, Some(String::from("Ready"))),
+            ]
+        );
+    }
+
+    #[test]
+    fn fields_iter_reports_unknown_variant_as_none() {
+        // mode = 0b11 = 3 has no named variant; ready = 0b0 = NotReady.
+        let reg = RegisterDebugValue::<u32, Status::Register>::new(0b011);
+        let mut fields: Vec<(&'static str, u32, Option<String>)> = Vec::new();
+        reg.fields_iter(|name, raw, decoded| {
+            fields.push((name, raw, decoded.map(|d| format!("{:?}", d))));
+        });
+        assert_eq!(
+            fields,
+            [
+                ("mode", 0b11, None),
+                ("ready", 0b0, Some(String::from("NotReady"))),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_lists_only_changed_fields_as_transitions() {
+        // mode is `On` in both snapshots and must be skipped; only `ready`
+        // changes, from `Ready` to `NotReady`.
+        let prev = RegisterDebugValue::<u32, Status::Register>::new(0b101);
+        let next = RegisterDebugValue::<u32, Status::Register>::new(0b001);
+        assert_eq!(
+            format!("{:?}", next.diff(&prev)),
+            "Status { ready: Ready -> NotReady }"
+        );
+    }
+
+    #[test]
+    fn diff_of_identical_snapshots_has_no_fields() {
+        let reg = RegisterDebugValue::<u32, Status::Register>::new(0b101);
+        assert_eq!(format!("{:?}", reg.diff(&reg)), "Status");
     }
 }
\ No newline at end of file